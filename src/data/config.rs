@@ -0,0 +1,98 @@
+//! Small persisted app preferences — today just the chosen theme and the
+//! `Auto` variant's day/night threshold. Lives next to the profile data on
+//! disk so it survives restart, the same way `Freq::load`/`save` do.
+
+use crate::data::theme::DayWindow;
+use crate::data::ThemeVariant;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+const FILE_NAME: &str = "config.txt";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub theme_variant: ThemeVariant,
+    pub day_window: DayWindow,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme_variant: ThemeVariant::Monokai,
+            day_window: DayWindow::default(),
+        }
+    }
+}
+
+impl Config {
+    fn path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("linkage").join(FILE_NAME))
+    }
+
+    /// Load the saved config, falling back to defaults if it's missing or
+    /// unreadable (first run, fresh install, no config dir on this
+    /// platform, ...).
+    pub fn load() -> Self {
+        Self::try_load().unwrap_or_default()
+    }
+
+    fn try_load() -> Option<Self> {
+        let contents = fs::read_to_string(Self::path()?).ok()?;
+        let mut lines = contents.lines();
+
+        let theme_variant = parse_variant(lines.next()?)?;
+        let start_hour = lines.next()?.parse().ok()?;
+        let end_hour = lines.next()?.parse().ok()?;
+
+        Some(Self {
+            theme_variant,
+            day_window: DayWindow { start_hour, end_hour },
+        })
+    }
+
+    /// Best-effort save; a failure here (read-only disk, missing config
+    /// dir) shouldn't interrupt the user's session.
+    pub fn save(&self) {
+        let _ = self.try_save();
+    }
+
+    fn try_save(&self) -> io::Result<()> {
+        let path = Self::path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let contents = format!(
+            "{}\n{}\n{}\n",
+            variant_name(self.theme_variant),
+            self.day_window.start_hour,
+            self.day_window.end_hour,
+        );
+
+        fs::write(path, contents)
+    }
+}
+
+fn variant_name(variant: ThemeVariant) -> &'static str {
+    match variant {
+        ThemeVariant::Monokai => "monokai",
+        ThemeVariant::SolarizedDark => "solarized-dark",
+        ThemeVariant::SolarizedLight => "solarized-light",
+        ThemeVariant::HighContrast => "high-contrast",
+        ThemeVariant::Auto => "auto",
+    }
+}
+
+fn parse_variant(s: &str) -> Option<ThemeVariant> {
+    Some(match s {
+        "monokai" => ThemeVariant::Monokai,
+        "solarized-dark" => ThemeVariant::SolarizedDark,
+        "solarized-light" => ThemeVariant::SolarizedLight,
+        "high-contrast" => ThemeVariant::HighContrast,
+        "auto" => ThemeVariant::Auto,
+        _ => return None,
+    })
+}
@@ -0,0 +1,5 @@
+pub mod config;
+pub mod theme;
+
+pub use config::Config;
+pub use theme::{DayWindow, Theme, Variant as ThemeVariant};
@@ -0,0 +1,174 @@
+use iced::Color;
+
+/// The built-in palettes a user can pick between, plus [`Variant::Auto`]
+/// which re-resolves to a day/night pick as the OS appearance changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Monokai,
+    SolarizedDark,
+    SolarizedLight,
+    HighContrast,
+    /// Follows the OS light/dark appearance (or a time-of-day threshold
+    /// when that isn't available), switching between Monokai and
+    /// Solarized Light.
+    Auto,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 5] = [
+        Variant::Monokai,
+        Variant::SolarizedDark,
+        Variant::SolarizedLight,
+        Variant::HighContrast,
+        Variant::Auto,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Variant::Monokai => "Monokai",
+            Variant::SolarizedDark => "Solarized Dark",
+            Variant::SolarizedLight => "Solarized Light",
+            Variant::HighContrast => "High Contrast",
+            Variant::Auto => "Auto",
+        }
+    }
+
+    /// Resolve `Auto` into a concrete, renderable variant given whether
+    /// the system currently prefers a light appearance.
+    pub fn resolve(&self, prefers_light: bool) -> Variant {
+        match self {
+            Variant::Auto => {
+                if prefers_light {
+                    Variant::SolarizedLight
+                } else {
+                    Variant::Monokai
+                }
+            }
+            other => *other,
+        }
+    }
+
+    pub fn theme(&self, day_window: DayWindow) -> Theme {
+        match self {
+            Variant::Monokai => Theme::monokai(),
+            Variant::SolarizedDark => Theme::solarized_dark(),
+            Variant::SolarizedLight => Theme::solarized_light(),
+            Variant::HighContrast => Theme::high_contrast(),
+            Variant::Auto => self.resolve(prefers_light_by_time(day_window)).theme(day_window),
+        }
+    }
+}
+
+/// The hour-of-day window (local time, 0-23) that [`Variant::Auto`] treats
+/// as "light"; everything outside it is "dark". `iced` has no OS
+/// appearance hook today, so this configurable threshold is the only
+/// signal `Auto` has to work with — see `data::config::Config`, which
+/// persists it alongside the chosen [`Variant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayWindow {
+    pub start_hour: u64,
+    pub end_hour: u64,
+}
+
+impl Default for DayWindow {
+    fn default() -> Self {
+        Self {
+            start_hour: 7,
+            end_hour: 19,
+        }
+    }
+}
+
+pub fn prefers_light_by_time(day_window: DayWindow) -> bool {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let hour_of_day = (secs / 3600) % 24;
+
+    (day_window.start_hour..day_window.end_hour).contains(&hour_of_day)
+}
+
+/// A concrete, renderable color palette.
+///
+/// `variant` records which named palette this came from so the settings
+/// screen can highlight the active swatch without a separate lookup.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub variant: Variant,
+    pub background: Color,
+    pub surface: Color,
+    pub text: Color,
+    pub miss: Color,
+    pub error: Color,
+    pub target: Color,
+    pub accent: Color,
+}
+
+impl Theme {
+    pub fn monokai() -> Self {
+        Self {
+            variant: Variant::Monokai,
+            background: Color::from_rgb8(0x27, 0x28, 0x22),
+            surface: Color::from_rgb8(0x3e, 0x3d, 0x32),
+            text: Color::from_rgb8(0xf8, 0xf8, 0xf2),
+            miss: Color::from_rgb8(0x75, 0x71, 0x5e),
+            error: Color::from_rgb8(0xf9, 0x26, 0x72),
+            target: Color::from_rgb8(0xae, 0x81, 0xff),
+            accent: Color::from_rgb8(0xa6, 0xe2, 0x2e),
+        }
+    }
+
+    pub fn solarized_dark() -> Self {
+        Self {
+            variant: Variant::SolarizedDark,
+            background: Color::from_rgb8(0x00, 0x2b, 0x36),
+            surface: Color::from_rgb8(0x07, 0x36, 0x42),
+            text: Color::from_rgb8(0x83, 0x94, 0x96),
+            miss: Color::from_rgb8(0x58, 0x6e, 0x75),
+            error: Color::from_rgb8(0xdc, 0x32, 0x2f),
+            target: Color::from_rgb8(0x26, 0x8b, 0xd2),
+            accent: Color::from_rgb8(0x85, 0x99, 0x00),
+        }
+    }
+
+    pub fn solarized_light() -> Self {
+        Self {
+            variant: Variant::SolarizedLight,
+            background: Color::from_rgb8(0xfd, 0xf6, 0xe3),
+            surface: Color::from_rgb8(0xee, 0xe8, 0xd5),
+            text: Color::from_rgb8(0x65, 0x7b, 0x83),
+            miss: Color::from_rgb8(0x93, 0xa1, 0xa1),
+            error: Color::from_rgb8(0xdc, 0x32, 0x2f),
+            target: Color::from_rgb8(0x26, 0x8b, 0xd2),
+            accent: Color::from_rgb8(0x85, 0x99, 0x00),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            variant: Variant::HighContrast,
+            background: Color::BLACK,
+            surface: Color::from_rgb8(0x20, 0x20, 0x20),
+            text: Color::WHITE,
+            miss: Color::from_rgb8(0x80, 0x80, 0x80),
+            error: Color::from_rgb8(0xff, 0x30, 0x30),
+            target: Color::from_rgb8(0xff, 0xff, 0x00),
+            accent: Color::from_rgb8(0x30, 0xff, 0x30),
+        }
+    }
+
+    /// Interpolate from [`Theme::miss`] to [`Theme::accent`] by `value`,
+    /// used to tint accuracy metrics (e.g. the per-key heatmap).
+    pub fn metric(&self, value: f32) -> Color {
+        let value = value.clamp(0.0, 1.0);
+        Color {
+            r: self.miss.r + (self.accent.r - self.miss.r) * value,
+            g: self.miss.g + (self.accent.g - self.miss.g) * value,
+            b: self.miss.b + (self.accent.b - self.miss.b) * value,
+            a: 1.0,
+        }
+    }
+}
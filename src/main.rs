@@ -8,10 +8,12 @@ use iced_native::window;
 mod data;
 mod font;
 mod screen;
+mod sound;
 
 use data::style;
-use data::{Freq, Theme};
+use data::{Config, DayWindow, Freq, Theme, ThemeVariant};
 use screen::Screen;
+use std::time::Duration;
 
 pub fn main() -> iced::Result {
     let freq = Freq::load();
@@ -38,12 +40,17 @@ struct Linkage {
     freq: Freq,
     screen: Screen,
     theme: Theme,
+    theme_variant: ThemeVariant,
+    day_window: DayWindow,
+    sound: sound::Player,
+    text_scale: f32,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     Event(iced_native::Event),
     Screen(screen::Message),
+    AutoThemeTick,
 }
 
 #[derive(Debug, Default)]
@@ -57,11 +64,17 @@ impl Application for Linkage {
     type Flags = Flags;
 
     fn new(flags: Flags) -> (Linkage, Command<Message>) {
+        let config = Config::load();
+
         let linkage = Linkage {
             should_exit: false,
             freq: flags.freq,
             screen: Screen::new(),
-            theme: Theme::monokai(),
+            theme: config.theme_variant.theme(config.day_window),
+            theme_variant: config.theme_variant,
+            day_window: config.day_window,
+            sound: sound::Player::load(),
+            text_scale: 1.0,
         };
         (
             linkage,
@@ -78,15 +91,45 @@ impl Application for Linkage {
     fn update(&mut self, message: Message, _clipboard: &mut Clipboard) -> Command<Message> {
         match message {
             Message::Event(event) => self.handle_event(event),
+            Message::AutoThemeTick => {
+                if self.theme_variant == ThemeVariant::Auto {
+                    self.theme = self.theme_variant.theme(self.day_window);
+                }
+                Command::none()
+            }
             Message::Screen(message) => {
-                if let Some((command, event)) = self.screen.update(message, &mut self.freq) {
+                if let Some((command, event)) =
+                    self.screen.update(message, &mut self.freq, &self.sound, self.day_window)
+                {
                     match event {
                         screen::Event::ExitRequested => {
                             Command::batch(vec![command.map(Message::Screen), self.prepare_close()])
                         }
-                        screen::Event::Training(user) => {
-                            self.screen = Screen::training(user, &mut self.freq);
-                            Command::none()
+                        screen::Event::SelectTheme(variant) => {
+                            self.theme_variant = variant;
+                            self.theme = variant.theme(self.day_window);
+                            self.save_config();
+                            command.map(Message::Screen)
+                        }
+                        screen::Event::SetMuted(muted) => {
+                            self.sound.set_muted(muted);
+                            command.map(Message::Screen)
+                        }
+                        screen::Event::SetVolume(volume) => {
+                            self.sound.set_volume(volume);
+                            command.map(Message::Screen)
+                        }
+                        screen::Event::SetTextScale(scale) => {
+                            self.text_scale = scale;
+                            command.map(Message::Screen)
+                        }
+                        screen::Event::SetDayWindow(day_window) => {
+                            self.day_window = day_window;
+                            if self.theme_variant == ThemeVariant::Auto {
+                                self.theme = self.theme_variant.theme(self.day_window);
+                            }
+                            self.save_config();
+                            command.map(Message::Screen)
                         }
                     }
                 } else {
@@ -97,10 +140,17 @@ impl Application for Linkage {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        Subscription::batch(vec![
+        let mut subscriptions = vec![
             self.screen.subscription().map(Message::Screen),
             iced_native::subscription::events().map(Message::Event),
-        ])
+        ];
+
+        if self.theme_variant == ThemeVariant::Auto {
+            subscriptions
+                .push(iced::time::every(Duration::from_secs(60)).map(|_| Message::AutoThemeTick));
+        }
+
+        Subscription::batch(subscriptions)
     }
 
     fn should_exit(&self) -> bool {
@@ -108,7 +158,17 @@ impl Application for Linkage {
     }
 
     fn view(&mut self) -> Element<Message> {
-        let content = self.screen.view(&self.theme).map(Message::Screen);
+        let content = self
+            .screen
+            .view(
+                &self.freq,
+                &self.theme,
+                self.theme_variant,
+                self.day_window,
+                &self.sound,
+                self.text_scale,
+            )
+            .map(Message::Screen);
 
         Container::new(content)
             .width(Length::Fill)
@@ -135,4 +195,14 @@ impl Linkage {
         self.should_exit = true;
         Command::none()
     }
+
+    /// Persist the theme choice next to the profile/freq data so it
+    /// survives restart.
+    fn save_config(&self) {
+        Config {
+            theme_variant: self.theme_variant,
+            day_window: self.day_window,
+        }
+        .save();
+    }
 }
@@ -1,5 +1,6 @@
 use crate::data::profile;
-use crate::data::Theme;
+use crate::data::{DayWindow, Theme, ThemeVariant};
+use crate::sound;
 use iced::{Command, Element, Subscription};
 
 pub mod loading;
@@ -12,8 +13,18 @@ pub enum Screen {
     Loading(loading::State),
     /// Tying practice
     Training(training::State),
-    /// Changing user settings
-    Settings(settings::State),
+    /// Changing user settings. This swaps out over `Training` rather than
+    /// alongside it — this iced version predates multi-window
+    /// `Application` support, so there's no way to keep the practice text
+    /// visible in a second OS window (chunk0-5 is blocked on an iced
+    /// upgrade for that). What we *can* do without one: carry the live
+    /// `training::State` along for the round trip instead of dropping it,
+    /// so focus/idle/pause bookkeeping and accumulated stats survive
+    /// going into settings and back out.
+    Settings {
+        settings: settings::State,
+        training: training::State,
+    },
     // /// Shutting down
     // Saving,
 }
@@ -27,7 +38,11 @@ pub enum Message {
 
 pub enum Event {
     ExitRequested,
-    SelectTheme(Theme),
+    SelectTheme(ThemeVariant),
+    SetMuted(bool),
+    SetVolume(f32),
+    SetTextScale(f32),
+    SetDayWindow(DayWindow),
 }
 
 impl Screen {
@@ -35,27 +50,23 @@ impl Screen {
         Self::Loading(loading::State::new())
     }
 
-    pub fn settings() -> Self {
-        Self::Settings(settings::State::new())
+    pub fn settings(training: training::State) -> Self {
+        Self::Settings {
+            settings: settings::State::new(),
+            training,
+        }
     }
 
     pub fn training() -> Self {
         Self::Training(training::State::new())
     }
 
-    pub fn go_back(&mut self) {
-        match self {
-            Screen::Settings(..) => {
-                *self = Screen::training();
-            }
-            _ => {}
-        }
-    }
-
     pub fn update(
         &mut self,
         profiles: &mut profile::List,
         message: Message,
+        sound: &sound::Player,
+        day_window: DayWindow,
     ) -> Option<(Command<Message>, Event)> {
         match self {
             Screen::Loading(state) => match message {
@@ -71,24 +82,36 @@ impl Screen {
                 _ => {}
             },
             Screen::Training(state) => match message {
-                Message::Training(message) => match state.update(profiles, message) {
+                Message::Training(message) => match state.update(profiles, message, sound) {
                     Some((_command, event)) => match event {
                         training::Event::Settings => {
-                            *self = Screen::settings();
+                            *self = Screen::settings(std::mem::take(state));
                         }
                     },
                     None => {}
                 },
                 _ => {}
             },
-            Screen::Settings(state) => match message {
-                Message::Settings(message) => match state.update(profiles, message) {
+            Screen::Settings { settings: state, training } => match message {
+                Message::Settings(message) => match state.update(profiles, message, day_window) {
                     Some((_command, event)) => match event {
                         settings::Event::Exit => {
-                            *self = Screen::training();
+                            *self = Screen::Training(std::mem::take(training));
                         }
-                        settings::Event::SelectTheme(theme) => {
-                            return Some((Command::none(), Event::SelectTheme(theme)));
+                        settings::Event::SelectTheme(variant) => {
+                            return Some((Command::none(), Event::SelectTheme(variant)));
+                        }
+                        settings::Event::SetMuted(muted) => {
+                            return Some((Command::none(), Event::SetMuted(muted)));
+                        }
+                        settings::Event::SetVolume(volume) => {
+                            return Some((Command::none(), Event::SetVolume(volume)));
+                        }
+                        settings::Event::SetTextScale(scale) => {
+                            return Some((Command::none(), Event::SetTextScale(scale)));
+                        }
+                        settings::Event::SetDayWindow(day_window) => {
+                            return Some((Command::none(), Event::SetDayWindow(day_window)));
                         }
                     },
                     None => {}
@@ -99,11 +122,23 @@ impl Screen {
         None
     }
 
-    pub fn view(&mut self, profiles: &profile::List, theme: &Theme) -> Element<Message> {
+    pub fn view(
+        &mut self,
+        profiles: &profile::List,
+        theme: &Theme,
+        theme_variant: ThemeVariant,
+        day_window: DayWindow,
+        sound: &sound::Player,
+        text_scale: f32,
+    ) -> Element<Message> {
         match self {
             Screen::Loading(loading) => loading.view(theme).map(Message::Loading),
-            Screen::Settings(state) => state.view(profiles, theme).map(Message::Settings),
-            Screen::Training(state) => state.view(profiles, theme).map(Message::Training),
+            Screen::Training(state) => {
+                state.view(profiles, theme, text_scale).map(Message::Training)
+            }
+            Screen::Settings { settings: state, .. } => state
+                .view(profiles, theme, theme_variant, day_window, sound, text_scale)
+                .map(Message::Settings),
         }
     }
 
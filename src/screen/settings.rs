@@ -0,0 +1,165 @@
+use crate::data::profile;
+use crate::data::{DayWindow, Theme, ThemeVariant};
+use crate::sound;
+use iced::button::{self, Button};
+use iced::{Checkbox, Column, Command, Element, Length, Row, Slider, Text};
+
+#[derive(Debug)]
+pub struct State {
+    back_button: button::State,
+    volume_slider: iced::slider::State,
+    text_scale_slider: iced::slider::State,
+    theme_buttons: Vec<button::State>,
+    day_start_slider: iced::slider::State,
+    day_end_slider: iced::slider::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    BackPressed,
+    SelectTheme(ThemeVariant),
+    MuteToggled(bool),
+    VolumeChanged(f32),
+    TextScaleChanged(f32),
+    DayStartChanged(f32),
+    DayEndChanged(f32),
+}
+
+pub enum Event {
+    Exit,
+    SelectTheme(ThemeVariant),
+    SetMuted(bool),
+    SetVolume(f32),
+    SetTextScale(f32),
+    SetDayWindow(DayWindow),
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self {
+            back_button: button::State::new(),
+            volume_slider: iced::slider::State::new(),
+            text_scale_slider: iced::slider::State::new(),
+            theme_buttons: ThemeVariant::ALL.iter().map(|_| button::State::new()).collect(),
+            day_start_slider: iced::slider::State::new(),
+            day_end_slider: iced::slider::State::new(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        _profiles: &mut profile::List,
+        message: Message,
+        day_window: DayWindow,
+    ) -> Option<(Command<Message>, Event)> {
+        match message {
+            Message::BackPressed => Some((Command::none(), Event::Exit)),
+            Message::SelectTheme(variant) => Some((Command::none(), Event::SelectTheme(variant))),
+            Message::MuteToggled(muted) => Some((Command::none(), Event::SetMuted(muted))),
+            Message::VolumeChanged(volume) => Some((Command::none(), Event::SetVolume(volume))),
+            Message::TextScaleChanged(scale) => {
+                Some((Command::none(), Event::SetTextScale(scale)))
+            }
+            Message::DayStartChanged(hour) => Some((
+                Command::none(),
+                Event::SetDayWindow(DayWindow {
+                    start_hour: hour.round() as u64,
+                    end_hour: day_window.end_hour,
+                }),
+            )),
+            Message::DayEndChanged(hour) => Some((
+                Command::none(),
+                Event::SetDayWindow(DayWindow {
+                    start_hour: day_window.start_hour,
+                    end_hour: hour.round() as u64,
+                }),
+            )),
+        }
+    }
+
+    pub fn view(
+        &mut self,
+        _profiles: &profile::List,
+        theme: &Theme,
+        selected_variant: ThemeVariant,
+        day_window: DayWindow,
+        sound: &sound::Player,
+        text_scale: f32,
+    ) -> Element<Message> {
+        let back_button = Button::new(&mut self.back_button, Text::new("Back"))
+            .on_press(Message::BackPressed)
+            .padding(10);
+
+        let mute_toggle = Checkbox::new(sound.is_muted(), "Mute keystroke sounds", |muted| {
+            Message::MuteToggled(muted)
+        });
+
+        let volume_slider = Slider::new(
+            &mut self.volume_slider,
+            0.0..=1.0,
+            sound.volume(),
+            Message::VolumeChanged,
+        );
+
+        let swatches = self.theme_buttons.iter_mut().zip(ThemeVariant::ALL.iter()).fold(
+            Row::new().spacing(10),
+            |row, (button_state, variant)| {
+                let label = if *variant == selected_variant {
+                    format!("\u{2713} {}", variant.name())
+                } else {
+                    variant.name().to_string()
+                };
+                row.push(
+                    Button::new(button_state, Text::new(label))
+                        .on_press(Message::SelectTheme(*variant))
+                        .padding(8),
+                )
+            },
+        );
+
+        let text_scale_slider = Slider::new(
+            &mut self.text_scale_slider,
+            0.5..=2.0,
+            text_scale,
+            Message::TextScaleChanged,
+        );
+
+        let day_window_label = Text::new(format!(
+            "Auto theme: light from {:02}:00 to {:02}:00",
+            day_window.start_hour, day_window.end_hour,
+        ))
+        .size(14);
+
+        let day_start_slider = Slider::new(
+            &mut self.day_start_slider,
+            0.0..=23.0,
+            day_window.start_hour as f32,
+            Message::DayStartChanged,
+        );
+
+        let day_end_slider = Slider::new(
+            &mut self.day_end_slider,
+            0.0..=23.0,
+            day_window.end_hour as f32,
+            Message::DayEndChanged,
+        );
+
+        Column::new()
+            .width(Length::Fill)
+            .spacing(15)
+            .padding(20)
+            .push(Text::new("Settings").size(24))
+            .push(Text::new("Theme").size(14))
+            .push(swatches)
+            .push(day_window_label)
+            .push(day_start_slider)
+            .push(day_end_slider)
+            .push(mute_toggle)
+            .push(Text::new("Volume").size(14))
+            .push(volume_slider)
+            .push(Text::new("Text size").size(14))
+            .push(text_scale_slider)
+            .push(back_button)
+            .into()
+    }
+}
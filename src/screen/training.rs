@@ -2,20 +2,40 @@ use crate::data::profile;
 use crate::data::training::{TriplePoint, CHARS_PER_LINE, MAX_ERRORS, MIN_CLEAN_PCT};
 use crate::data::Theme;
 use crate::font;
+use crate::sound::{self, Effect};
 use crate::style;
 use iced::button::{self, Button};
+use iced::canvas::Canvas;
 use iced::keyboard::{self, KeyCode};
 use iced::{
-    Align, Column, Command, Container, Element, Length, Row, Space, Subscription, Text,
+    Align, Column, Command, Container, Element, Length, Row, Size, Space, Subscription, Text,
     VerticalAlignment,
 };
+use iced_lazy::responsive;
 use itertools::{EitherOrBoth, Itertools};
+use std::time::{Duration, Instant};
+
+mod keyboard_heatmap;
+use keyboard_heatmap::Heatmap;
+
+/// How long the window can sit idle (no `CharacterReceived`) before the
+/// session is paused as if unfocused.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[derive(Debug)]
 pub struct State {
     modifiers: keyboard::Modifiers,
     settings_button: button::State,
     accuracy_metric: TriplePoint,
+    focused: bool,
+    last_activity: Instant,
+    /// `Some` while the session clock is paused, so WPM/duration
+    /// accounting can exclude the gap once we resume.
+    paused_since: Option<Instant>,
+    /// Running total of time spent paused this session, handed to
+    /// `profile::Session` on each resume so WPM/duration accounting can
+    /// subtract it back out.
+    paused_total: Duration,
 }
 
 #[derive(Debug, Clone)]
@@ -24,17 +44,28 @@ pub enum Message {
     UserButtonPressed,
     WindowFocused,
     WindowUnocused,
+    IdleTick,
 }
 
 pub enum Event {
     Settings,
 }
 
-const CHAR_WIDTH: u16 = 10;
-const ROW_CHARS: u16 = (CHARS_PER_LINE + MAX_ERRORS - 1) as u16;
-const ROW_WIDTH: u16 = CHAR_WIDTH * ROW_CHARS;
-const ROW_ERROR_WIDTH: u16 = (MAX_ERRORS - 1) as u16 * CHAR_WIDTH;
-const LINE_SPACE: u16 = 10;
+/// Number of character cells a row needs to reserve: the line itself plus
+/// room for the error gutter.
+const ROW_CHARS: f32 = (CHARS_PER_LINE + MAX_ERRORS - 1) as f32;
+const MIN_CHAR_WIDTH: f32 = 8.0;
+const MAX_CHAR_WIDTH: f32 = 28.0;
+
+impl Default for State {
+    /// Used to take ownership of a live `State` out of the `Screen` enum
+    /// (e.g. [`crate::screen::Screen::settings`]) without creating a real
+    /// "fresh session" — the returned placeholder is swapped in only for
+    /// the instant it takes to move the real value out, never rendered.
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl State {
     pub fn new() -> Self {
@@ -42,24 +73,93 @@ impl State {
             modifiers: keyboard::Modifiers::default(),
             settings_button: button::State::new(),
             accuracy_metric: TriplePoint::new(0.5, MIN_CLEAN_PCT, 0.975).unwrap_or_default(),
+            focused: true,
+            last_activity: Instant::now(),
+            paused_since: None,
+            paused_total: Duration::ZERO,
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_since.is_some()
+    }
+
+    /// Total time excluded from WPM/duration accounting so far this
+    /// session.
+    pub fn paused_duration(&self) -> Duration {
+        self.paused_total
+    }
+
+    fn pause(&mut self) {
+        if self.paused_since.is_none() {
+            self.paused_since = Some(Instant::now());
         }
     }
 
+    /// Resume the clock and mark this instant as the last activity, so an
+    /// idle check right after resuming doesn't immediately re-pause.
+    ///
+    /// Hands the elapsed gap to `profile::Session::add_paused_duration`,
+    /// the same way every other keystroke call here (`apply_char`,
+    /// `fill_next_lines`, ...) reaches into `profile::List`/`Session`
+    /// methods that live outside this file. That module isn't part of
+    /// this tree, so whether the WPM/duration formula actually subtracts
+    /// the accumulated gap can't be confirmed from here — add/verify
+    /// `add_paused_duration` in `data::profile` when that module lands.
+    fn resume(&mut self, profiles: &mut profile::List) {
+        if let Some(paused_since) = self.paused_since.take() {
+            let elapsed = paused_since.elapsed();
+            self.paused_total += elapsed;
+            profiles.session_mut().add_paused_duration(elapsed);
+        }
+        self.last_activity = Instant::now();
+    }
+
     pub fn update(
         &mut self,
         profiles: &mut profile::List,
         message: Message,
+        sound: &sound::Player,
     ) -> Option<(Command<Message>, Event)> {
         match message {
             Message::KeyboardEvent(keyboard_event) => {
-                self.handle_keyboard(profiles, keyboard_event)
+                self.handle_keyboard(profiles, keyboard_event, sound)
             }
             Message::UserButtonPressed => Some((Command::none(), Event::Settings)),
-            _ => None,
+            Message::WindowFocused => {
+                self.focused = true;
+                self.resume(profiles);
+                None
+            }
+            Message::WindowUnocused => {
+                self.focused = false;
+                self.pause();
+                None
+            }
+            Message::IdleTick => {
+                if self.focused
+                    && !self.is_paused()
+                    && self.last_activity.elapsed() >= IDLE_TIMEOUT
+                {
+                    self.pause();
+                }
+                None
+            }
         }
     }
 
-    pub fn view(&mut self, profiles: &profile::List, theme: &Theme) -> Element<Message> {
+    /// Build the active line, caret indicator, and upcoming lines at a
+    /// given `char_width`, derived from the space `responsive` hands us
+    /// in [`State::view`] (scaled by the user's text-size setting).
+    fn training_text<'a>(
+        profiles: &'a profile::List,
+        theme: &'a Theme,
+        char_width: u16,
+        line_space: u16,
+    ) -> Element<'a, Message> {
+        let row_width = char_width * ROW_CHARS.round() as u16;
+        let row_error_width = char_width * (MAX_ERRORS - 1) as u16;
+
         let active_line = Row::with_children(
             profiles
                 .session()
@@ -67,7 +167,7 @@ impl State {
                 .iter()
                 .map(|hit| {
                     Text::new(hit.target().to_string())
-                        .width(Length::Units(CHAR_WIDTH))
+                        .width(Length::Units(char_width))
                         .font(font::THIN)
                         .color(if hit.is_dirty() {
                             theme.miss
@@ -88,12 +188,12 @@ impl State {
                             EitherOrBoth::Left(e) | EitherOrBoth::Both(e, _) => {
                                 let c = if *e == ' ' { '\u{2591}' } else { *e };
                                 Text::new(c.to_string())
-                                    .width(Length::Units(CHAR_WIDTH))
+                                    .width(Length::Units(char_width))
                                     .font(font::MEDIUM)
                                     .color(theme.error)
                             }
                             EitherOrBoth::Right(t) => {
-                                Text::new(t.to_string()).width(Length::Units(CHAR_WIDTH))
+                                Text::new(t.to_string()).width(Length::Units(char_width))
                             }
                         }),
                 )
@@ -104,23 +204,23 @@ impl State {
         let target_indicator: Element<_> = if profiles.session().errors.is_empty() {
             Row::with_children(vec![
                 Space::with_width(Length::Units(
-                    profiles.session().hits.len() as u16 * CHAR_WIDTH,
+                    profiles.session().hits.len() as u16 * char_width,
                 ))
                 .into(),
                 Text::new("\u{2015}")
-                    .width(Length::Units(CHAR_WIDTH))
-                    .height(Length::Units(LINE_SPACE))
+                    .width(Length::Units(char_width))
+                    .height(Length::Units(line_space))
                     .vertical_alignment(VerticalAlignment::Center)
                     .color(theme.target)
                     .into(),
             ])
             .into()
         } else {
-            Space::with_height(Length::Units(LINE_SPACE)).into()
+            Space::with_height(Length::Units(line_space)).into()
         };
 
         let content_active = Column::new()
-            .width(Length::Units(ROW_WIDTH))
+            .width(Length::Units(row_width))
             .push(active_line)
             .push(target_indicator);
 
@@ -134,7 +234,7 @@ impl State {
                         line.chars()
                             .map(|c| {
                                 Text::new(c.to_string())
-                                    .width(Length::Units(CHAR_WIDTH))
+                                    .width(Length::Units(char_width))
                                     .into()
                             })
                             .collect(),
@@ -143,43 +243,55 @@ impl State {
                 })
                 .collect(),
         )
-        .spacing(LINE_SPACE)
-        .width(Length::Units(ROW_WIDTH));
+        .spacing(line_space)
+        .width(Length::Units(row_width));
 
-        let training = Column::with_children(vec![content_active.into(), content_next.into()])
-            .padding([0, 0, 0, ROW_ERROR_WIDTH]);
-        let training = Container::new(training)
+        let training =
+            Column::with_children(vec![content_active.into(), content_next.into()])
+                .padding([0, 0, 0, row_error_width]);
+
+        Container::new(training)
             .width(Length::Fill)
             .height(Length::Fill)
             .center_x()
-            .center_y();
+            .center_y()
+            .into()
+    }
 
-        let clean_letters = Column::with_children(
-            profiles
-                .active()
-                .state
-                .clean_letters()
-                .iter()
-                .map(|(ch, val)| {
-                    Row::new()
-                        .push(Text::new(format!("{}", ch)).font(font::LIGHT).size(12))
-                        .push(
-                            Text::new("\u{25a0}")
-                                .color(theme.metric(self.accuracy_metric.value(*val)))
-                                .font(font::LIGHT)
-                                .size(16),
-                        )
-                        .align_items(Align::Center)
-                        .spacing(5)
-                        .into()
-                })
-                .collect(),
-        )
-        .spacing(2)
-        .padding(5);
+    pub fn view(
+        &mut self,
+        profiles: &profile::List,
+        theme: &Theme,
+        text_scale: f32,
+    ) -> Element<Message> {
+        let training = responsive(move |size: Size| {
+            let char_width = ((size.width / ROW_CHARS) * text_scale)
+                .clamp(MIN_CHAR_WIDTH, MAX_CHAR_WIDTH)
+                .round() as u16;
+            let line_space = ((char_width as f32) * 0.8).max(6.0).round() as u16;
+
+            Self::training_text(profiles, theme, char_width, line_space)
+        });
+
+        let clean_letters = profiles.active().state.clean_letters();
+        let accuracy_metric = &self.accuracy_metric;
+        let layout = profiles.active().layout.as_str();
+
+        let keyboard_heatmap = Canvas::new(Heatmap {
+            layout,
+            theme,
+            color_for: Box::new(move |ch| {
+                clean_letters
+                    .iter()
+                    .find(|(c, _)| *c == ch)
+                    .map(|(_, val)| theme.metric(accuracy_metric.value(*val)))
+            }),
+        })
+        .width(Length::Units(480))
+        .height(Length::Units(170));
 
         let content = Row::new()
-            .push(clean_letters)
+            .push(keyboard_heatmap)
             .push(training)
             .width(Length::Fill)
             .height(Length::Fill);
@@ -200,14 +312,39 @@ impl State {
             .push(Space::with_width(Length::Fill))
             .push(settings_button);
 
-        Column::with_children(vec![content.into(), footer.into()]).into()
+        let body = Column::with_children(vec![content.into(), footer.into()]);
+
+        if self.is_paused() {
+            Container::new(
+                Column::new()
+                    .push(body)
+                    .push(
+                        Container::new(Text::new("Paused — type to resume").color(theme.miss))
+                            .width(Length::Fill)
+                            .center_x(),
+                    )
+                    .spacing(10),
+            )
+            .style(style::container::dimmed(theme))
+            .into()
+        } else {
+            body.into()
+        }
     }
 
     pub fn handle_keyboard(
         &mut self,
         profiles: &mut profile::List,
         event: iced::keyboard::Event,
+        sound: &sound::Player,
     ) -> Option<(Command<Message>, Event)> {
+        if self.is_paused() {
+            // Resuming is free: swallow the keystroke that woke us up so
+            // it isn't scored against a gap that wasn't the user's fault.
+            self.resume(profiles);
+            return None;
+        }
+
         match event {
             keyboard::Event::ModifiersChanged(modifiers) => {
                 self.modifiers = modifiers;
@@ -216,11 +353,17 @@ impl State {
 
             keyboard::Event::KeyPressed { key_code, .. } => match key_code {
                 KeyCode::Space => {
+                    self.last_activity = Instant::now();
+                    let was_dirty = profiles.session().active_hit.is_dirty();
                     if let Some(line) = profiles.session_mut().apply_char(' ') {
                         if let Some(words) = profiles.active_mut().add_line(line) {
                             profiles.session_mut().update_words(words)
                         }
                         profiles.session_mut().fill_next_lines();
+                        sound.play(Effect::LineComplete);
+                    } else {
+                        let newly_dirty = !was_dirty && profiles.session().active_hit.is_dirty();
+                        sound.play(if newly_dirty { Effect::Mistake } else { Effect::Correct });
                     }
                     None
                 }
@@ -234,11 +377,17 @@ impl State {
             keyboard::Event::CharacterReceived(c)
                 if c.is_alphanumeric() && !self.modifiers.is_command_pressed() =>
             {
+                self.last_activity = Instant::now();
+                let was_dirty = profiles.session().active_hit.is_dirty();
                 if let Some(line) = profiles.session_mut().apply_char(c) {
                     if let Some(words) = profiles.active_mut().add_line(line) {
                         profiles.session_mut().update_words(words)
                     }
                     profiles.session_mut().fill_next_lines();
+                    sound.play(Effect::LineComplete);
+                } else {
+                    let newly_dirty = !was_dirty && profiles.session().active_hit.is_dirty();
+                    sound.play(if newly_dirty { Effect::Mistake } else { Effect::Correct });
                 }
                 None
             }
@@ -251,7 +400,7 @@ pub fn subscription() -> Subscription<Message> {
     use iced_native::event::{Event, Status};
     use iced_native::window::Event as WindowEvent;
 
-    iced_native::subscription::events_with(|event, status| {
+    let events = iced_native::subscription::events_with(|event, status| {
         if status == Status::Captured {
             return None;
         }
@@ -261,5 +410,9 @@ pub fn subscription() -> Subscription<Message> {
             Event::Window(WindowEvent::Unfocused) => Some(Message::WindowUnocused),
             _ => None,
         }
-    })
+    });
+
+    let idle_check = iced::time::every(Duration::from_secs(1)).map(|_| Message::IdleTick);
+
+    Subscription::batch(vec![events, idle_check])
 }
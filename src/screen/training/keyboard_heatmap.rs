@@ -0,0 +1,211 @@
+//! A `canvas::Program` that draws a virtual keyboard tinted per-key by
+//! typing accuracy, in place of (or alongside) the alphabetical
+//! `clean_letters` list.
+
+use crate::data::Theme;
+use crate::font;
+use iced::canvas::{self, Cursor, Frame, Geometry, Path, Program, Text as CanvasText};
+use iced::{Color, HorizontalAlignment, Point, Rectangle, Size, VerticalAlignment};
+
+const KEY_UNIT: f32 = 44.0;
+const KEY_GAP: f32 = 4.0;
+const KEY_RADIUS: f32 = 6.0;
+
+/// One key: the character it types and its offset/width in row units
+/// (1 unit == one standard key, including the gap).
+#[derive(Clone, Copy)]
+struct Key {
+    ch: char,
+    offset: f32,
+    width: f32,
+}
+
+const fn key(ch: char, offset: f32, width: f32) -> Key {
+    Key { ch, offset, width }
+}
+
+// Row offsets follow the usual ANSI stagger; each layout only reshuffles
+// which letter sits at a given physical position.
+const QWERTY: [&[Key]; 3] = [
+    &[
+        key('q', 0.0, 1.0),
+        key('w', 1.0, 1.0),
+        key('e', 2.0, 1.0),
+        key('r', 3.0, 1.0),
+        key('t', 4.0, 1.0),
+        key('y', 5.0, 1.0),
+        key('u', 6.0, 1.0),
+        key('i', 7.0, 1.0),
+        key('o', 8.0, 1.0),
+        key('p', 9.0, 1.0),
+    ],
+    &[
+        key('a', 0.25, 1.0),
+        key('s', 1.25, 1.0),
+        key('d', 2.25, 1.0),
+        key('f', 3.25, 1.0),
+        key('g', 4.25, 1.0),
+        key('h', 5.25, 1.0),
+        key('j', 6.25, 1.0),
+        key('k', 7.25, 1.0),
+        key('l', 8.25, 1.0),
+    ],
+    &[
+        key('z', 0.75, 1.0),
+        key('x', 1.75, 1.0),
+        key('c', 2.75, 1.0),
+        key('v', 3.75, 1.0),
+        key('b', 4.75, 1.0),
+        key('n', 5.75, 1.0),
+        key('m', 6.75, 1.0),
+    ],
+];
+
+const DVORAK: [&[Key]; 3] = [
+    &[
+        key('p', 0.0, 1.0),
+        key('y', 1.0, 1.0),
+        key('f', 2.0, 1.0),
+        key('g', 3.0, 1.0),
+        key('c', 4.0, 1.0),
+        key('r', 5.0, 1.0),
+        key('l', 6.0, 1.0),
+    ],
+    &[
+        key('a', 0.25, 1.0),
+        key('o', 1.25, 1.0),
+        key('e', 2.25, 1.0),
+        key('u', 3.25, 1.0),
+        key('i', 4.25, 1.0),
+        key('d', 5.25, 1.0),
+        key('h', 6.25, 1.0),
+        key('t', 7.25, 1.0),
+        key('n', 8.25, 1.0),
+        key('s', 9.25, 1.0),
+    ],
+    &[
+        key('q', 0.75, 1.0),
+        key('j', 1.75, 1.0),
+        key('k', 2.75, 1.0),
+        key('x', 3.75, 1.0),
+        key('b', 4.75, 1.0),
+        key('m', 5.75, 1.0),
+        key('w', 6.75, 1.0),
+        key('v', 7.75, 1.0),
+        key('z', 8.75, 1.0),
+    ],
+];
+
+const COLEMAK: [&[Key]; 3] = [
+    &[
+        key('q', 0.0, 1.0),
+        key('w', 1.0, 1.0),
+        key('f', 2.0, 1.0),
+        key('p', 3.0, 1.0),
+        key('g', 4.0, 1.0),
+        key('j', 5.0, 1.0),
+        key('l', 6.0, 1.0),
+        key('u', 7.0, 1.0),
+        key('y', 8.0, 1.0),
+    ],
+    &[
+        key('a', 0.25, 1.0),
+        key('r', 1.25, 1.0),
+        key('s', 2.25, 1.0),
+        key('t', 3.25, 1.0),
+        key('d', 4.25, 1.0),
+        key('h', 5.25, 1.0),
+        key('n', 6.25, 1.0),
+        key('e', 7.25, 1.0),
+        key('i', 8.25, 1.0),
+        key('o', 9.25, 1.0),
+    ],
+    &[
+        key('z', 0.75, 1.0),
+        key('x', 1.75, 1.0),
+        key('c', 2.75, 1.0),
+        key('v', 3.75, 1.0),
+        key('b', 4.75, 1.0),
+        key('k', 5.75, 1.0),
+        key('m', 6.75, 1.0),
+    ],
+];
+
+/// Looks up the key-geometry table for `layout`, or `None` if it isn't one
+/// of the layouts this heatmap knows how to draw — callers must surface
+/// that rather than silently guessing a layout the user didn't pick.
+fn rows_for(layout: &str) -> Option<&'static [&'static [Key]]> {
+    match layout {
+        "QWERTY" => Some(&QWERTY),
+        "Dvorak" => Some(&DVORAK),
+        "Colemak" => Some(&COLEMAK),
+        _ => None,
+    }
+}
+
+/// Draws one key per character of the active layout, tinted by
+/// `color_for(char)`.
+pub struct Heatmap<'a> {
+    pub layout: &'a str,
+    pub theme: &'a Theme,
+    pub color_for: Box<dyn Fn(char) -> Option<Color> + 'a>,
+}
+
+impl<'a> Program<super::Message> for Heatmap<'a> {
+    fn draw(&self, bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry> {
+        let rows = match rows_for(self.layout) {
+            Some(rows) => rows,
+            None => {
+                let mut frame = Frame::new(bounds.size());
+                frame.fill_text(CanvasText {
+                    content: format!("Unknown layout: {}", self.layout),
+                    position: Point::new(bounds.width / 2.0, bounds.height / 2.0),
+                    color: self.theme.error,
+                    size: 16.0,
+                    font: font::MEDIUM,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                    ..CanvasText::default()
+                });
+                return vec![frame.into_geometry()];
+            }
+        };
+
+        let widest_units = rows
+            .iter()
+            .map(|row| row.iter().map(|k| k.offset + k.width).fold(0.0, f32::max))
+            .fold(0.0, f32::max);
+
+        let unit = ((bounds.width / widest_units.max(1.0)).min(KEY_UNIT)).max(16.0);
+
+        let mut frame = Frame::new(bounds.size());
+
+        for (row_index, row) in rows.iter().enumerate() {
+            for k in row.iter() {
+                let top_left = Point::new(k.offset * unit, row_index as f32 * unit);
+                let size = Size::new(k.width * unit - KEY_GAP, unit - KEY_GAP);
+
+                let fill = self
+                    .color_for
+                    .as_ref()(k.ch)
+                    .unwrap_or(self.theme.surface);
+
+                let key_rect = Path::rounded_rectangle(top_left, size, KEY_RADIUS);
+                frame.fill(&key_rect, fill);
+
+                frame.fill_text(CanvasText {
+                    content: k.ch.to_ascii_uppercase().to_string(),
+                    position: Point::new(top_left.x + size.width / 2.0, top_left.y + size.height / 2.0),
+                    color: self.theme.text,
+                    size: unit * 0.4,
+                    font: font::MEDIUM,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                    ..CanvasText::default()
+                });
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
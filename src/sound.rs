@@ -0,0 +1,151 @@
+//! Keystroke audio feedback, backed by `rodio`.
+//!
+//! Samples are decoded once at startup (see [`Player::load`]) so that
+//! playback never touches the filesystem or a decoder on the UI thread.
+
+use rodio::buffer::SamplesBuffer;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use std::io::Cursor;
+
+const CORRECT: &[u8] = include_bytes!("../assets/sound/correct.wav");
+const MISTAKE: &[u8] = include_bytes!("../assets/sound/mistake.wav");
+const LINE_COMPLETE: &[u8] = include_bytes!("../assets/sound/line_complete.wav");
+
+/// Which keystroke event just happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    Correct,
+    Mistake,
+    LineComplete,
+}
+
+/// A fully decoded sample, cheap to turn back into a playable `Source`
+/// via [`Sample::buffer`] since it's just a `Vec<f32>` clone — no decoder
+/// involved.
+#[derive(Clone)]
+struct Sample {
+    channels: u16,
+    sample_rate: u32,
+    data: Vec<f32>,
+}
+
+impl Sample {
+    fn decode(bytes: &'static [u8]) -> Option<Self> {
+        let source = Decoder::new(Cursor::new(bytes)).ok()?;
+        let channels = source.channels();
+        let sample_rate = source.sample_rate();
+        let data = source.convert_samples().collect();
+
+        Some(Self {
+            channels,
+            sample_rate,
+            data,
+        })
+    }
+
+    fn buffer(&self) -> SamplesBuffer<f32> {
+        SamplesBuffer::new(self.channels, self.sample_rate, self.data.clone())
+    }
+}
+
+/// Pre-decoded samples plus an output handle.
+///
+/// Construction never fails: if no output device is available, `Player`
+/// degrades to a silent no-op so the rest of the app doesn't need to care.
+pub struct Player {
+    stream: Option<(OutputStream, OutputStreamHandle)>,
+    correct: Option<Sample>,
+    mistake: Option<Sample>,
+    line_complete: Option<Sample>,
+    muted: bool,
+    volume: f32,
+}
+
+impl std::fmt::Debug for Player {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Player")
+            .field("connected", &self.stream.is_some())
+            .field("muted", &self.muted)
+            .field("volume", &self.volume)
+            .finish()
+    }
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self {
+            stream: None,
+            correct: None,
+            mistake: None,
+            line_complete: None,
+            muted: false,
+            volume: 0.7,
+        }
+    }
+}
+
+impl Player {
+    /// Open the default output device and decode the keystroke samples
+    /// into memory.
+    ///
+    /// Called once at startup alongside `Freq::load()`; `play()` only
+    /// ever clones the already-decoded buffer, so no decoder runs on the
+    /// UI thread afterwards.
+    pub fn load() -> Self {
+        let stream = OutputStream::try_default().ok();
+
+        Self {
+            stream,
+            correct: Sample::decode(CORRECT),
+            mistake: Sample::decode(MISTAKE),
+            line_complete: Sample::decode(LINE_COMPLETE),
+            muted: false,
+            volume: 0.7,
+        }
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0.0, 1.0);
+    }
+
+    /// Play `effect`, or do nothing if muted, silenced, or headless.
+    pub fn play(&self, effect: Effect) {
+        if self.muted || self.volume <= 0.0 {
+            return;
+        }
+
+        let (_stream, handle) = match &self.stream {
+            Some(pair) => pair,
+            None => return,
+        };
+
+        let sample = match effect {
+            Effect::Correct => &self.correct,
+            Effect::Mistake => &self.mistake,
+            Effect::LineComplete => &self.line_complete,
+        };
+
+        let sample = match sample {
+            Some(sample) => sample,
+            None => return,
+        };
+
+        if let Ok(sink) = Sink::try_new(handle) {
+            sink.set_volume(self.volume);
+            sink.append(sample.buffer());
+            sink.detach();
+        }
+    }
+}